@@ -4,13 +4,27 @@
 
 use axum::{
     body::{Body, Bytes},
-    extract::{Path, Query, State},
-    http::{header::CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue, StatusCode},
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Path, Query, State,
+    },
+    http::{
+        header::{
+            ACCEPT_ENCODING, ACCEPT_RANGES, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_RANGE,
+            CONTENT_TYPE, RANGE, VARY,
+        },
+        HeaderMap, HeaderName, HeaderValue, StatusCode,
+    },
     response::{IntoResponse, Response},
     routing::{get, post},
     Router,
 };
-use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use bytes::Buf;
+use flate2::{
+    read::{DeflateDecoder, GzDecoder},
+    write::{DeflateEncoder, GzEncoder},
+    Compression,
+};
 use hyper_util::rt::TokioIo;
 use rand::{distributions::Alphanumeric, Rng};
 use serde::{Deserialize, Serialize};
@@ -21,7 +35,12 @@ use std::{
     path::{Component, Path as StdPath, PathBuf},
     time::Duration,
 };
-use tokio::{fs, net::TcpListener, time::sleep};
+use tokio::{
+    fs,
+    io::{AsyncReadExt, AsyncSeekExt},
+    net::TcpListener,
+    time::sleep,
+};
 
 /// CPU-bound Fibonacci computation
 fn fibonacci(n: u32) -> u64 {
@@ -178,63 +197,166 @@ async fn body(Query(params): Query<BodyParams>) -> String {
     random_string(size)
 }
 
-/// POST /body-codec - Gzip decode/encode stress test
-async fn body_codec(headers: HeaderMap, body: Bytes) -> Response {
-    let mut data = if let Some(enc) = headers.get("content-encoding") {
-        let enc = enc.to_str().unwrap_or("");
-        if enc.to_ascii_lowercase().contains("gzip") {
-            let mut decoder = GzDecoder::new(body.as_ref());
-            let mut decoded = Vec::new();
-            if decoder.read_to_end(&mut decoded).is_err() {
+/// Content-encoding algorithms supported by `/body-codec`
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    Identity,
+    Gzip,
+    Deflate,
+    Brotli,
+    Zstd,
+}
+
+impl Encoding {
+    fn as_str(self) -> &'static str {
+        match self {
+            Encoding::Identity => "identity",
+            Encoding::Gzip => "gzip",
+            Encoding::Deflate => "deflate",
+            Encoding::Brotli => "br",
+            Encoding::Zstd => "zstd",
+        }
+    }
+
+    fn from_str(name: &str) -> Option<Encoding> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "identity" => Some(Encoding::Identity),
+            "gzip" | "x-gzip" => Some(Encoding::Gzip),
+            "deflate" => Some(Encoding::Deflate),
+            "br" => Some(Encoding::Brotli),
+            "zstd" => Some(Encoding::Zstd),
+            _ => None,
+        }
+    }
+}
+
+/// Parse an `Accept-Encoding` header (with `q=` weights) and return the
+/// highest-weighted algorithm we support, or `Encoding::Identity` if none is.
+fn negotiate_response_encoding(accept_encoding: &str) -> Encoding {
+    accept_encoding
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';');
+            let name = parts.next()?.trim();
+            let encoding = Encoding::from_str(name)?;
+            let q = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .and_then(|v| v.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            (q > 0.0).then_some((encoding, q))
+        })
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(encoding, _)| encoding)
+        .unwrap_or(Encoding::Identity)
+}
+
+fn decode_body(encoding: Encoding, body: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut decoded = Vec::new();
+    match encoding {
+        Encoding::Identity => decoded.extend_from_slice(body),
+        Encoding::Gzip => {
+            GzDecoder::new(body).read_to_end(&mut decoded)?;
+        }
+        Encoding::Deflate => {
+            DeflateDecoder::new(body).read_to_end(&mut decoded)?;
+        }
+        Encoding::Brotli => {
+            brotli::Decompressor::new(body, 4096).read_to_end(&mut decoded)?;
+        }
+        Encoding::Zstd => decoded = zstd::stream::decode_all(body)?,
+    }
+    Ok(decoded)
+}
+
+fn encode_body(encoding: Encoding, data: &[u8], level: u32) -> std::io::Result<Vec<u8>> {
+    match encoding {
+        Encoding::Identity => Ok(data.to_vec()),
+        Encoding::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::new(level.min(9)));
+            encoder.write_all(data)?;
+            encoder.finish()
+        }
+        Encoding::Deflate => {
+            let mut encoder = DeflateEncoder::new(Vec::new(), Compression::new(level.min(9)));
+            encoder.write_all(data)?;
+            encoder.finish()
+        }
+        Encoding::Brotli => {
+            let mut encoded = Vec::new();
+            {
+                let mut writer = brotli::CompressorWriter::new(&mut encoded, 4096, level.min(11), 22);
+                writer.write_all(data)?;
+            }
+            Ok(encoded)
+        }
+        Encoding::Zstd => zstd::stream::encode_all(data, level as i32),
+    }
+}
+
+#[derive(Deserialize)]
+struct BodyCodecParams {
+    level: Option<u32>,
+}
+
+/// POST /body-codec - content-encoding decode/encode stress test
+async fn body_codec(headers: HeaderMap, Query(params): Query<BodyCodecParams>, body: Bytes) -> Response {
+    let request_encoding = match headers
+        .get(CONTENT_ENCODING)
+        .and_then(|v| v.to_str().ok())
+        .filter(|v| !v.is_empty())
+    {
+        Some(value) => match Encoding::from_str(value) {
+            Some(encoding) => encoding,
+            None => {
                 return Response::builder()
-                    .status(StatusCode::BAD_REQUEST)
-                    .body(Body::from("Invalid gzip body"))
+                    .status(StatusCode::UNSUPPORTED_MEDIA_TYPE)
+                    .body(Body::from(format!("Unsupported Content-Encoding: {}", value)))
                     .unwrap();
             }
-            decoded
-        } else {
-            body.to_vec()
+        },
+        None => Encoding::Identity,
+    };
+
+    let mut data = match decode_body(request_encoding, &body) {
+        Ok(data) => data,
+        Err(_) => {
+            return Response::builder()
+                .status(StatusCode::BAD_REQUEST)
+                .body(Body::from(format!("Invalid {} body", request_encoding.as_str())))
+                .unwrap();
         }
-    } else {
-        body.to_vec()
     };
 
     for byte in data.iter_mut() {
         *byte = byte.wrapping_add(1);
     }
 
-    let mut response = Response::builder()
-        .status(StatusCode::OK)
-        .header(CONTENT_TYPE, "application/octet-stream");
-
-    let accept = headers
-        .get("accept-encoding")
+    let response_encoding = headers
+        .get(ACCEPT_ENCODING)
         .and_then(|v| v.to_str().ok())
-        .unwrap_or("");
-    if accept.to_ascii_lowercase().contains("gzip") {
-        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
-        if encoder.write_all(&data).is_err() {
+        .map(negotiate_response_encoding)
+        .unwrap_or(Encoding::Identity);
+    let level = params.level.unwrap_or(6);
+
+    let encoded = match encode_body(response_encoding, &data, level) {
+        Ok(encoded) => encoded,
+        Err(_) => {
             return Response::builder()
                 .status(StatusCode::INTERNAL_SERVER_ERROR)
                 .body(Body::from("Compression failed"))
                 .unwrap();
         }
-        let compressed = match encoder.finish() {
-            Ok(buf) => buf,
-            Err(_) => {
-                return Response::builder()
-                    .status(StatusCode::INTERNAL_SERVER_ERROR)
-                    .body(Body::from("Compression failed"))
-                    .unwrap();
-            }
-        };
+    };
+
+    let mut response = Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "application/octet-stream");
+    if response_encoding != Encoding::Identity {
         response = response
-            .header("content-encoding", "gzip")
-            .header("vary", "Accept-Encoding");
-        return response.body(Body::from(compressed)).unwrap();
+            .header(CONTENT_ENCODING, response_encoding.as_str())
+            .header(VARY, "Accept-Encoding");
     }
-
-    response.body(Body::from(data)).unwrap()
+    response.body(Body::from(encoded)).unwrap()
 }
 
 /// GET /status - Server status endpoint
@@ -243,10 +365,56 @@ async fn status() -> axum::Json<serde_json::Value> {
         "server": "rust-axum",
         "status": "ok",
         "h2": std::env::var("BENCH_H2").unwrap_or_default() == "1",
-        "tls": std::env::var("BENCH_TLS").unwrap_or_default() == "1"
+        "h3": std::env::var("BENCH_H3").unwrap_or_default() == "1",
+        "tls": std::env::var("BENCH_TLS").unwrap_or_default() == "1",
+        "ws": true
     }))
 }
 
+#[derive(Deserialize)]
+struct WsParams {
+    frames: Option<usize>,
+    size: Option<usize>,
+}
+
+/// GET /ws - WebSocket echo and server-push throughput endpoint
+async fn ws_handler(ws: WebSocketUpgrade, Query(params): Query<WsParams>) -> Response {
+    ws.on_upgrade(move |socket| ws_session(socket, params.frames, params.size))
+}
+
+/// Echo incoming frames back to the client; if `frames` is set, first push that
+/// many server-initiated binary messages of `size` bytes (default 64).
+async fn ws_session(mut socket: WebSocket, frames: Option<usize>, size: Option<usize>) {
+    if let Some(frames) = frames {
+        let size = size.unwrap_or(64);
+        for _ in 0..frames {
+            if socket
+                .send(Message::Binary(vec![0u8; size]))
+                .await
+                .is_err()
+            {
+                return;
+            }
+        }
+    }
+
+    while let Some(Ok(message)) = socket.recv().await {
+        let reply = match message {
+            Message::Text(text) => Message::Text(text),
+            Message::Binary(data) => Message::Binary(data),
+            Message::Ping(data) => Message::Pong(data),
+            Message::Pong(_) => continue,
+            Message::Close(frame) => {
+                let _ = socket.send(Message::Close(frame)).await;
+                return;
+            }
+        };
+        if socket.send(reply).await.is_err() {
+            return;
+        }
+    }
+}
+
 /// Route handler for /r{N} literal routes
 /// Pattern route: /users/{id}/posts/{post}
 async fn user_post(Path((user_id, post_id)): Path<(String, String)>) -> String {
@@ -271,6 +439,54 @@ fn get_port() -> u16 {
         .unwrap_or(8086)
 }
 
+fn get_host() -> Option<String> {
+    env::var("BENCH_HOST").ok()
+}
+
+/// Resolve the address(es) to bind. An explicit host binds a single address;
+/// otherwise bind both `0.0.0.0` and `[::]` so v4 and v6 clients can both connect.
+fn resolve_addrs(host: Option<&str>, port: u16) -> Vec<SocketAddr> {
+    match host {
+        Some(host) => {
+            let ip: std::net::IpAddr = host.parse().expect("invalid --host/BENCH_HOST value");
+            vec![SocketAddr::new(ip, port)]
+        }
+        None => vec![
+            SocketAddr::from(([0, 0, 0, 0], port)),
+            SocketAddr::from((std::net::Ipv6Addr::UNSPECIFIED, port)),
+        ],
+    }
+}
+
+/// Bind a TCP listener, forcing `IPV6_V6ONLY` on IPv6 addresses so the
+/// `[::]` listener doesn't grab the dual-stack range and collide with a
+/// separately bound `0.0.0.0` listener.
+fn bind_tcp(addr: SocketAddr) -> std::io::Result<std::net::TcpListener> {
+    let domain = if addr.is_ipv6() { socket2::Domain::IPV6 } else { socket2::Domain::IPV4 };
+    let socket = socket2::Socket::new(domain, socket2::Type::STREAM, Some(socket2::Protocol::TCP))?;
+    if addr.is_ipv6() {
+        socket.set_only_v6(true)?;
+    }
+    socket.set_reuse_address(true)?;
+    socket.bind(&addr.into())?;
+    socket.listen(1024)?;
+    socket.set_nonblocking(true)?;
+    Ok(socket.into())
+}
+
+/// Bind a UDP socket, forcing `IPV6_V6ONLY` on IPv6 addresses for the same
+/// reason as `bind_tcp` above.
+fn bind_udp(addr: SocketAddr) -> std::io::Result<std::net::UdpSocket> {
+    let domain = if addr.is_ipv6() { socket2::Domain::IPV6 } else { socket2::Domain::IPV4 };
+    let socket = socket2::Socket::new(domain, socket2::Type::DGRAM, Some(socket2::Protocol::UDP))?;
+    if addr.is_ipv6() {
+        socket.set_only_v6(true)?;
+    }
+    socket.bind(&addr.into())?;
+    socket.set_nonblocking(true)?;
+    Ok(socket.into())
+}
+
 fn get_threads() -> usize {
     env::var("BENCH_THREADS")
         .ok()
@@ -301,9 +517,12 @@ async fn async_main(threads: usize) {
     let mut static_dir: Option<PathBuf> = None;
     let mut route_count: usize = 0;
     let mut h2_enabled = false;
+    let mut h3_enabled = false;
     let mut tls_enabled = false;
     let mut cert_file: Option<String> = None;
     let mut key_file: Option<String> = None;
+    let mut unix_socket: Option<PathBuf> = None;
+    let mut host_override: Option<String> = None;
 
     let mut i = 1;
     while i < args.len() {
@@ -324,6 +543,10 @@ async fn async_main(threads: usize) {
                 h2_enabled = true;
                 i += 1;
             }
+            "--h3" => {
+                h3_enabled = true;
+                i += 1;
+            }
             "--tls" => {
                 tls_enabled = true;
                 i += 1;
@@ -336,6 +559,14 @@ async fn async_main(threads: usize) {
                 key_file = Some(args[i + 1].clone());
                 i += 2;
             }
+            "--unix" if i + 1 < args.len() => {
+                unix_socket = Some(PathBuf::from(&args[i + 1]));
+                i += 2;
+            }
+            "--host" if i + 1 < args.len() => {
+                host_override = Some(args[i + 1].clone());
+                i += 2;
+            }
             "--help" | "-h" => {
                 println!(
                     "Usage: {} [options]\n\
@@ -344,9 +575,12 @@ async fn async_main(threads: usize) {
                        --static DIR  Static files directory\n  \
                        --routes N    Number of /r{{N}} routes\n  \
                        --h2          Enable HTTP/2\n  \
+                       --h3          Enable HTTP/3 over QUIC (requires --cert and --key)\n  \
                        --tls         Enable TLS (requires --cert and --key)\n  \
                        --cert FILE   TLS certificate file (PEM)\n  \
                        --key FILE    TLS private key file (PEM)\n  \
+                       --unix PATH   Listen on a Unix domain socket instead of TCP\n  \
+                       --host HOST   Bind address (default: both 0.0.0.0 and [::], env: BENCH_HOST)\n  \
                        --help        Show this help",
                     args[0]
                 );
@@ -360,12 +594,15 @@ async fn async_main(threads: usize) {
     if h2_enabled {
         env::set_var("BENCH_H2", "1");
     }
+    if h3_enabled {
+        env::set_var("BENCH_H3", "1");
+    }
     if tls_enabled {
         env::set_var("BENCH_TLS", "1");
     }
 
     let port = port_override.unwrap_or(port);
-    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let addrs = resolve_addrs(host_override.or_else(get_host).as_deref(), port);
 
     let app_state = AppState { static_dir: static_dir.clone() };
 
@@ -378,7 +615,8 @@ async fn async_main(threads: usize) {
         .route("/json", get(json_endpoint))
         .route("/delay", get(delay))
         .route("/body", get(body))
-        .route("/status", get(status));
+        .route("/status", get(status))
+        .route("/ws", get(ws_handler));
 
     // Add static file serving if configured
     if static_dir.is_some() {
@@ -398,7 +636,9 @@ async fn async_main(threads: usize) {
 
     let app = app.with_state(app_state);
 
-    let protocol = if h2_enabled {
+    let protocol = if h3_enabled {
+        "h3"
+    } else if h2_enabled {
         if tls_enabled { "h2-tls" } else { "h2c" }
     } else {
         "http/1.1"
@@ -411,42 +651,264 @@ async fn async_main(threads: usize) {
         println!("Routes: {} literal + pattern routes", route_count);
     }
 
-    if tls_enabled {
+    if let Some(path) = unix_socket {
+        serve_unix(&path, app).await;
+    } else if h3_enabled {
+        // HTTP/3 over QUIC using h3/quinn (QUIC mandates TLS, so --cert/--key are required)
+        let cert = cert_file.expect("--cert required for --h3");
+        let key = key_file.expect("--key required for --h3");
+        match addrs.as_slice() {
+            [a] => serve_h3(*a, &cert, &key, app).await,
+            [a, b] => {
+                tokio::join!(
+                    serve_h3(*a, &cert, &key, app.clone()),
+                    serve_h3(*b, &cert, &key, app),
+                );
+            }
+            _ => unreachable!("resolve_addrs only ever returns 1 or 2 addresses"),
+        }
+    } else if tls_enabled {
         // HTTP/2 over TLS using axum-server with rustls (binds its own listener)
         let cert = cert_file.expect("--cert required for TLS");
         let key = key_file.expect("--key required for TLS");
         let config = axum_server::tls_rustls::RustlsConfig::from_pem_file(&cert, &key)
             .await
             .expect("Failed to load TLS config");
-        axum_server::bind_rustls(addr, config)
-            .serve(app.into_make_service())
-            .await
-            .unwrap();
+        match addrs.as_slice() {
+            [a] => {
+                let listener = bind_tcp(*a).expect("failed to bind TLS listener");
+                axum_server::from_tcp_rustls(listener, config)
+                    .serve(app.into_make_service())
+                    .await
+                    .unwrap();
+            }
+            [a, b] => {
+                let listener_a = bind_tcp(*a).expect("failed to bind TLS listener");
+                let listener_b = bind_tcp(*b).expect("failed to bind TLS listener");
+                let (r1, r2) = tokio::join!(
+                    axum_server::from_tcp_rustls(listener_a, config.clone())
+                        .serve(app.clone().into_make_service()),
+                    axum_server::from_tcp_rustls(listener_b, config).serve(app.into_make_service()),
+                );
+                r1.unwrap();
+                r2.unwrap();
+            }
+            _ => unreachable!("resolve_addrs only ever returns 1 or 2 addresses"),
+        }
+    } else {
+        match addrs.as_slice() {
+            [a] => serve_tcp(*a, h2_enabled, app).await,
+            [a, b] => {
+                tokio::join!(
+                    serve_tcp(*a, h2_enabled, app.clone()),
+                    serve_tcp(*b, h2_enabled, app),
+                );
+            }
+            _ => unreachable!("resolve_addrs only ever returns 1 or 2 addresses"),
+        }
+    }
+}
+
+/// Accept loop driving the `Router` over plain TCP, either HTTP/1.1
+/// (`axum::serve`) or HTTP/2 cleartext (h2c, via hyper directly).
+async fn serve_tcp(addr: SocketAddr, h2_enabled: bool, app: Router) {
+    let listener = TcpListener::from_std(bind_tcp(addr).expect("failed to bind TCP listener")).unwrap();
+    if h2_enabled {
+        // HTTP/2 cleartext (h2c) using hyper directly
+        loop {
+            let (stream, _addr) = listener.accept().await.unwrap();
+            let io = TokioIo::new(stream);
+            let svc = app.clone();
+            tokio::spawn(async move {
+                let hyper_service = hyper_util::service::TowerToHyperService::new(svc);
+                let builder = hyper_util::server::conn::auto::Builder::new(hyper_util::rt::TokioExecutor::new());
+                if let Err(err) = builder.serve_connection(io, hyper_service).await {
+                    eprintln!("h2c connection error: {}", err);
+                }
+            });
+        }
     } else {
-        let listener = TcpListener::bind(addr).await.unwrap();
-        if h2_enabled {
-            // HTTP/2 cleartext (h2c) using hyper directly
+        axum::serve(listener, app).await.unwrap();
+    }
+}
+
+/// Accept loop driving the `Router` over a Unix domain socket.
+async fn serve_unix(path: &StdPath, app: Router) {
+    if path.exists() {
+        std::fs::remove_file(path).expect("failed to remove stale --unix socket file");
+    }
+    let listener = tokio::net::UnixListener::bind(path).expect("failed to bind --unix socket");
+    println!("Listening on unix:{}", path.display());
+
+    let cleanup_path = path.to_path_buf();
+    tokio::spawn(async move {
+        let mut sigterm = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to register SIGTERM handler");
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {}
+            _ = sigterm.recv() => {}
+        }
+        let _ = std::fs::remove_file(&cleanup_path);
+        std::process::exit(0);
+    });
+
+    loop {
+        let (stream, _addr) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(err) => {
+                eprintln!("unix accept error: {}", err);
+                continue;
+            }
+        };
+        let io = TokioIo::new(stream);
+        let svc = app.clone();
+        tokio::spawn(async move {
+            let hyper_service = hyper_util::service::TowerToHyperService::new(svc);
+            let builder = hyper_util::server::conn::auto::Builder::new(hyper_util::rt::TokioExecutor::new());
+            if let Err(err) = builder.serve_connection(io, hyper_service).await {
+                eprintln!("unix connection error: {}", err);
+            }
+        });
+    }
+}
+
+/// Accept loop driving the `Router` over HTTP/3 (QUIC) via quinn.
+async fn serve_h3(addr: SocketAddr, cert: &str, key: &str, app: Router) {
+    let certs = rustls_pemfile::certs(&mut std::io::BufReader::new(
+        std::fs::File::open(cert).expect("failed to open --cert file"),
+    ))
+    .collect::<Result<Vec<_>, _>>()
+    .expect("failed to parse --cert file");
+    let private_key = rustls_pemfile::private_key(&mut std::io::BufReader::new(
+        std::fs::File::open(key).expect("failed to open --key file"),
+    ))
+    .expect("failed to parse --key file")
+    .expect("no private key found in --key file");
+
+    let mut tls_config = rustls::ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(certs, private_key)
+        .expect("invalid TLS certificate/key pair");
+    tls_config.alpn_protocols = vec![b"h3".to_vec()];
+
+    let server_config = quinn::ServerConfig::with_crypto(std::sync::Arc::new(
+        quinn::crypto::rustls::QuicServerConfig::try_from(tls_config).expect("invalid QUIC TLS config"),
+    ));
+    let socket = bind_udp(addr).expect("failed to bind QUIC socket");
+    let runtime = quinn::default_runtime().expect("no async runtime found");
+    let endpoint = quinn::Endpoint::new(
+        quinn::EndpointConfig::default(),
+        Some(server_config),
+        socket,
+        runtime,
+    )
+    .expect("failed to create QUIC endpoint");
+
+    while let Some(incoming) = endpoint.accept().await {
+        let app = app.clone();
+        tokio::spawn(async move {
+            let conn = match incoming.await {
+                Ok(conn) => conn,
+                Err(err) => {
+                    eprintln!("h3 handshake error: {}", err);
+                    return;
+                }
+            };
+            let mut h3_conn = match h3::server::Connection::new(h3_quinn::Connection::new(conn)).await {
+                Ok(conn) => conn,
+                Err(err) => {
+                    eprintln!("h3 connection error: {}", err);
+                    return;
+                }
+            };
+
             loop {
-                let (stream, _addr) = listener.accept().await.unwrap();
-                let io = TokioIo::new(stream);
-                let svc = app.clone();
-                tokio::spawn(async move {
-                    let hyper_service = hyper_util::service::TowerToHyperService::new(svc);
-                    let builder = hyper_util::server::conn::auto::Builder::new(hyper_util::rt::TokioExecutor::new());
-                    if let Err(err) = builder.serve_connection(io, hyper_service).await {
-                        eprintln!("h2c connection error: {}", err);
+                match h3_conn.accept().await {
+                    Ok(Some((req, stream))) => {
+                        let svc = app.clone();
+                        tokio::spawn(async move {
+                            if let Err(err) = serve_h3_request(req, stream, svc).await {
+                                eprintln!("h3 request error: {}", err);
+                            }
+                        });
                     }
-                });
+                    Ok(None) => break,
+                    Err(err) => {
+                        eprintln!("h3 accept error: {}", err);
+                        break;
+                    }
+                }
             }
-        } else {
-            axum::serve(listener, app).await.unwrap();
+        });
+    }
+}
+
+/// Drive a single HTTP/3 request/response through the `Router` by buffering
+/// the request body, calling the service, and sending the buffered response back.
+async fn serve_h3_request<S>(
+    req: http::Request<()>,
+    mut stream: h3::server::RequestStream<h3_quinn::BidiStream<Bytes>, Bytes>,
+    mut service: S,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    S: tower::Service<http::Request<Body>, Response = Response> + Send,
+    S::Future: Send,
+{
+    let mut body = Vec::new();
+    while let Some(chunk) = stream.recv_data().await? {
+        body.extend_from_slice(chunk.chunk());
+    }
+
+    let (parts, ()) = req.into_parts();
+    let request = http::Request::from_parts(parts, Body::from(body));
+
+    let response = service
+        .call(request)
+        .await
+        .map_err(|_| "service call failed")?;
+    let (parts, body) = response.into_parts();
+
+    stream
+        .send_response(http::Response::from_parts(parts, ()))
+        .await?;
+    let bytes = axum::body::to_bytes(body, usize::MAX).await?;
+    stream.send_data(bytes).await?;
+    stream.finish().await?;
+    Ok(())
+}
+
+/// Parse a `Range: bytes=...` value against a file of length `len`, supporting
+/// the `start-end`, `start-` and `-suffix` forms. Returns `Some(Ok((start, end)))`
+/// (inclusive, `end` clamped to `len - 1`), `Some(Err(()))` when the range is
+/// unsatisfiable, or `None` when the value is not a parseable single `bytes=` range.
+fn parse_byte_range(range: &str, len: u64) -> Option<Result<(u64, u64), ()>> {
+    let spec = range.strip_prefix("bytes=")?;
+    let (start, end) = if let Some(suffix) = spec.strip_prefix('-') {
+        let suffix_len: u64 = suffix.parse().ok()?;
+        if suffix_len == 0 || len == 0 {
+            return Some(Err(()));
         }
+        (len.saturating_sub(suffix_len), len - 1)
+    } else {
+        let mut parts = spec.splitn(2, '-');
+        let start: u64 = parts.next()?.parse().ok()?;
+        let end = match parts.next() {
+            Some("") | None => len.saturating_sub(1),
+            Some(end) => end.parse().ok()?,
+        };
+        (start, end)
+    };
+
+    if start >= len || start > end {
+        return Some(Err(()));
     }
+    Some(Ok((start, end.min(len.saturating_sub(1)))))
 }
 
 async fn static_file(
     State(state): State<AppState>,
     Path(file_path): Path<String>,
+    headers: HeaderMap,
 ) -> Response {
     let base_dir = match &state.static_dir {
         Some(dir) => dir.clone(),
@@ -467,23 +929,61 @@ async fn static_file(
         return StatusCode::FORBIDDEN.into_response();
     }
 
+    let mut file = match fs::File::open(&sanitized).await {
+        Ok(file) => file,
+        Err(_) => return StatusCode::NOT_FOUND.into_response(),
+    };
+    let len = match file.metadata().await {
+        Ok(meta) => meta.len(),
+        Err(_) => return StatusCode::NOT_FOUND.into_response(),
+    };
 
-    match fs::read(&sanitized).await {
-        Ok(content) => {
-            let mime = match sanitized.extension().and_then(|ext| ext.to_str()) {
-                Some("html") => "text/html",
-                Some("css") => "text/css",
-                Some("js") => "application/javascript",
-                Some("json") => "application/json",
-                _ => "application/octet-stream",
-            };
+    let mime = match sanitized.extension().and_then(|ext| ext.to_str()) {
+        Some("html") => "text/html",
+        Some("css") => "text/css",
+        Some("js") => "application/javascript",
+        Some("json") => "application/json",
+        _ => "application/octet-stream",
+    };
+
+    let range = headers
+        .get(RANGE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| parse_byte_range(v, len));
+
+    match range {
+        Some(Err(())) => Response::builder()
+            .status(StatusCode::RANGE_NOT_SATISFIABLE)
+            .header(CONTENT_RANGE, format!("bytes */{}", len))
+            .header(ACCEPT_RANGES, "bytes")
+            .body(Body::empty())
+            .unwrap(),
+        Some(Ok((start, end))) => {
+            let slice_len = end - start + 1;
+            let mut buf = vec![0u8; slice_len as usize];
+            if file.seek(std::io::SeekFrom::Start(start)).await.is_err()
+                || file.read_exact(&mut buf).await.is_err()
+            {
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
 
             Response::builder()
-                .status(StatusCode::OK)
+                .status(StatusCode::PARTIAL_CONTENT)
                 .header(CONTENT_TYPE, mime)
-                .body(Body::from(content))
+                .header(CONTENT_RANGE, format!("bytes {}-{}/{}", start, end, len))
+                .header(CONTENT_LENGTH, slice_len.to_string())
+                .header(ACCEPT_RANGES, "bytes")
+                .body(Body::from(buf))
                 .unwrap()
         }
-        Err(_) => StatusCode::NOT_FOUND.into_response(),
+        None => match fs::read(&sanitized).await {
+            Ok(content) => Response::builder()
+                .status(StatusCode::OK)
+                .header(CONTENT_TYPE, mime)
+                .header(ACCEPT_RANGES, "bytes")
+                .body(Body::from(content))
+                .unwrap(),
+            Err(_) => StatusCode::NOT_FOUND.into_response(),
+        },
     }
 }